@@ -1,70 +1,490 @@
 //! Hub FVM API Client
 
+use std::time::Duration;
+
 use anyhow::{Error, Result};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use url::Url;
 
+use crate::fvm::error::FvmError;
 use crate::fvm::{Channel, PackageSet, PackageSetRecord};
 
+use super::{DefaultTransport, HttpTransport, ReleaseInfo};
+
+/// The FVM release signing key, embedded at build time so signature
+/// verification works without a network round-trip to fetch it.
+const RELEASE_SIGNING_KEY: &str =
+    include_str!("../../../assets/fvm-release-signing-key.asc");
+
+/// `User-Agent` sent to crates.io, per their crawler policy
+const CRATES_IO_USER_AGENT: &str = "fluvio-hub-util (https://github.com/fluvio-community/fluvio)";
+
+/// The subset of the crates.io `GET /api/v1/crates/{name}` response this
+/// client cares about
+#[derive(Debug, Deserialize)]
+struct CratesIoResponse {
+    versions: Vec<CrateVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateVersion {
+    num: String,
+    #[serde(default)]
+    yanked: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ApiError {
     pub status: u16,
     pub message: String,
 }
 
+/// Controls how thoroughly an artifact fetched by
+/// [`Client::fetch_package_set`] is checked before being returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerifyMode {
+    /// Perform no integrity or authenticity checking
+    None,
+    /// Verify the SHA-256 checksum published in the manifest
+    #[default]
+    Checksum,
+    /// Verify the checksum and a detached OpenPGP signature fetched
+    /// alongside the artifact
+    ChecksumAndSignature,
+}
+
+/// Controls how [`Client`] retries a request against its configured
+/// mirrors before giving up.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts against a single mirror before moving on
+    /// to the next one
+    pub max_attempts: usize,
+    /// Base delay used to compute exponential backoff between attempts
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Computes the delay to wait before retrying the given zero-indexed
+    /// `attempt`, as exponential backoff plus jitter.
+    fn backoff(&self, attempt: usize) -> Duration {
+        let exponential = self.base_delay.saturating_mul(1u32 << attempt.min(16) as u32);
+        let jitter = Duration::from_millis(
+            rand::thread_rng().gen_range(0..=self.base_delay.as_millis() as u64),
+        );
+
+        exponential + jitter
+    }
+}
+
 /// HTTP Client for interacting with the Hub FVM API
-pub struct Client {
-    api_url: Url,
+///
+/// Generic over an [`HttpTransport`] so tests can substitute a mock
+/// implementation instead of performing real network requests. Production
+/// code gets [`DefaultTransport`] via [`Client::new`].
+pub struct Client<H: HttpTransport = DefaultTransport> {
+    /// Base URLs to try in order: the primary Hub, then any mirrors
+    mirrors: Vec<Url>,
+    retry_policy: RetryPolicy,
+    transport: H,
 }
 
-impl Client {
+impl Client<DefaultTransport> {
     /// Creates a new [`Client`] with the default Hub API URL
     pub fn new(url: &str) -> Result<Self> {
-        let api_url = url.parse::<Url>()?;
+        Self::with_mirrors(&[url])
+    }
+
+    /// Creates a new [`Client`] that tries `urls` in order, falling back to
+    /// later entries when earlier ones fail
+    pub fn with_mirrors(urls: &[&str]) -> Result<Self> {
+        Self::with_transport_and_mirrors(urls, DefaultTransport)
+    }
+}
+
+impl<H: HttpTransport> Client<H> {
+    /// Creates a new [`Client`] backed by a custom [`HttpTransport`],
+    /// primarily useful for testing against a mock server.
+    pub fn with_transport(url: &str, transport: H) -> Result<Self> {
+        Self::with_transport_and_mirrors(&[url], transport)
+    }
+
+    /// Creates a new [`Client`] backed by a custom [`HttpTransport`] that
+    /// tries `urls` in order.
+    pub fn with_transport_and_mirrors(urls: &[&str], transport: H) -> Result<Self> {
+        let mirrors = urls
+            .iter()
+            .map(|url| url.parse::<Url>().map_err(|_| FvmError::CannotParseUrl))
+            .collect::<std::result::Result<Vec<_>, FvmError>>()?;
 
-        Ok(Self { api_url })
+        if mirrors.is_empty() {
+            return Err(FvmError::CannotParseUrl.into());
+        }
+
+        Ok(Self {
+            mirrors,
+            retry_policy: RetryPolicy::default(),
+            transport,
+        })
     }
 
-    /// Fetches a [`PackageSet`] from the Hub with the specific [`Channel`]
-    pub async fn fetch_package_set(&self, channel: &Channel, arch: &str) -> Result<PackageSet> {
+    /// Overrides the default [`RetryPolicy`] used against each mirror
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// The primary (first) configured mirror
+    fn primary_mirror(&self) -> &Url {
+        &self.mirrors[0]
+    }
+
+    /// Fetches a [`PackageSet`] from the Hub with the specific [`Channel`],
+    /// verifying the referenced artifact according to `verify`.
+    ///
+    /// Tries each configured mirror in turn, retrying each one according to
+    /// the [`RetryPolicy`] on 5xx responses or transport errors.
+    pub async fn fetch_package_set(
+        &self,
+        channel: &Channel,
+        arch: &str,
+        verify: VerifyMode,
+    ) -> Result<PackageSet> {
         use crate::htclient::ResponseExt;
         use std::collections::HashMap;
 
-        let url = self.make_fetch_package_set_url(channel)?;
-        let res = crate::htclient::get(url)
-            .await
-            .map_err(|err| Error::msg(err.to_string()))?;
+        let res = self
+            .get_with_retry(|base| self.make_fetch_package_set_url(base, channel))
+            .await?;
         let res_status = res.status();
 
         if res_status.is_success() {
-            let manifest = res.json::<HashMap<String, PackageSetRecord>>().map_err(|err| {
-                tracing::debug!(?err, "Failed to parse manifest from GitHub releases");
-                Error::msg("Failed to parse manifest file")
-            })?;
+            let manifest = res
+                .json::<HashMap<String, PackageSetRecord>>()
+                .map_err(|err| {
+                    tracing::debug!(?err, "Failed to parse manifest from GitHub releases");
+                    FvmError::MalformedManifest
+                })?;
 
             let pkgset_record = manifest
                 .get(arch)
-                .ok_or_else(|| Error::msg(format!("Architecture '{}' not found in manifest", arch)))?;
+                .ok_or_else(|| FvmError::ArchNotFound(arch.to_string()))?;
 
             tracing::info!(?pkgset_record, "Found PackageSet");
+
+            if verify != VerifyMode::None {
+                self.verify_artifact(pkgset_record, verify).await?;
+            }
+
             return Ok(pkgset_record.clone().into());
         }
 
-        let error = res.json::<ApiError>().map_err(|err| {
-            tracing::debug!(?err, "Failed to parse API Error");
-            Error::msg(format!("Server responded with status code {res_status}"))
-        })?;
+        let error = res
+            .json::<ApiError>()
+            .map_err(|_| FvmError::UnexpectedStatus(res_status.as_u16()))?;
 
         tracing::debug!(?error, "Server responded with not successful status code");
 
         Err(anyhow::anyhow!(error.message))
     }
 
-    /// Builds the URL to fetch a [`PackageSet`] manifest from GitHub releases
-    /// using the [`Client`]'s `api_url`.
+    /// Performs a `GET` built from each mirror in turn (via `make_url`),
+    /// retrying each mirror according to [`Client`]'s [`RetryPolicy`] on
+    /// 5xx responses or transport errors, before moving on to the next
+    /// mirror.
+    async fn get_with_retry(
+        &self,
+        make_url: impl Fn(&Url) -> Result<Url>,
+    ) -> Result<crate::htclient::HttpResponse> {
+        let mut last_err: Option<FvmError> = None;
+
+        for mirror in &self.mirrors {
+            let url = make_url(mirror)?;
+
+            for attempt in 0..self.retry_policy.max_attempts {
+                match self.transport.get(url.clone()).await {
+                    Ok(res) if !res.status().is_server_error() => return Ok(res),
+                    Ok(res) => {
+                        tracing::debug!(status = %res.status(), %url, "Mirror responded with a server error, retrying");
+                        last_err = Some(FvmError::UnexpectedStatus(res.status().as_u16()));
+                    }
+                    Err(err) => {
+                        tracing::debug!(?err, %url, "Transport error while contacting mirror, retrying");
+                        last_err = Some(FvmError::AllMirrorsFailed);
+                    }
+                }
+
+                if attempt + 1 < self.retry_policy.max_attempts {
+                    tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or(FvmError::AllMirrorsFailed).into())
+    }
+
+    /// Downloads the artifact referenced by `record` and verifies its
+    /// SHA-256 checksum, and optionally its detached OpenPGP signature,
+    /// according to `verify`.
+    async fn verify_artifact(&self, record: &PackageSetRecord, verify: VerifyMode) -> Result<()> {
+        if record.checksum.is_empty() {
+            return Err(FvmError::ChecksumMissing {
+                url: record.download_url.to_string(),
+            }
+            .into());
+        }
+
+        let res = self
+            .transport
+            .get(record.download_url.clone())
+            .await
+            .map_err(|err| Error::msg(err.to_string()))?;
+
+        if !res.status().is_success() {
+            return Err(FvmError::DownloadFailed {
+                url: record.download_url.to_string(),
+                status: res.status().as_u16(),
+            }
+            .into());
+        }
+
+        let artifact = res.bytes();
+
+        let mut hasher = Sha256::new();
+        hasher.update(artifact);
+        let digest = hasher.finalize();
+
+        let expected = decode_checksum(&record.checksum).ok_or_else(|| FvmError::ChecksumMismatch {
+            url: record.download_url.to_string(),
+            expected: record.checksum.clone(),
+            actual: hex::encode(digest),
+        })?;
+
+        if !constant_time_eq(digest.as_slice(), &expected) {
+            return Err(FvmError::ChecksumMismatch {
+                url: record.download_url.to_string(),
+                expected: record.checksum.clone(),
+                actual: hex::encode(digest),
+            }
+            .into());
+        }
+
+        if verify == VerifyMode::ChecksumAndSignature {
+            let sig_url = Url::parse(&format!("{}.sig", record.download_url))?;
+            let sig_res = self
+                .transport
+                .get(sig_url)
+                .await
+                .map_err(|err| Error::msg(err.to_string()))?;
+
+            if !sig_res.status().is_success() {
+                return Err(FvmError::DownloadFailed {
+                    url: format!("{}.sig", record.download_url),
+                    status: sig_res.status().as_u16(),
+                }
+                .into());
+            }
+
+            let signature_armored = std::str::from_utf8(sig_res.bytes())
+                .map_err(|_| FvmError::SignatureInvalid {
+                    url: record.download_url.to_string(),
+                })?;
+
+            verify_signature(RELEASE_SIGNING_KEY, signature_armored, artifact).map_err(|_| {
+                FvmError::SignatureInvalid {
+                    url: record.download_url.to_string(),
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Lists up to `limit` non-draft releases from the GitHub Releases API,
+    /// most recent first.
+    pub async fn list_releases(&self, limit: usize) -> Result<Vec<ReleaseInfo>> {
+        use crate::htclient::ResponseExt as _;
+
+        let (owner, repo) = self.owner_and_repo()?;
+        let mut url = Some(Url::parse(&format!(
+            "https://api.github.com/repos/{owner}/{repo}/releases?per_page={limit}"
+        ))?);
+
+        let mut releases = Vec::with_capacity(limit);
+
+        while let Some(next_url) = url.take() {
+            let res = self
+                .transport
+                .get_with_user_agent(next_url, "fluvio-hub-util")
+                .await
+                .map_err(|err| Error::msg(err.to_string()))?;
+
+            if !res.status().is_success() {
+                return Err(anyhow::anyhow!(
+                    "GitHub Releases API responded with status code {}",
+                    res.status()
+                ));
+            }
+
+            let page = res.json::<Vec<ReleaseInfo>>().map_err(|err| {
+                tracing::debug!(?err, "Failed to parse releases page");
+                Error::msg("Failed to parse releases from GitHub")
+            })?;
+
+            releases.extend(page.into_iter().filter(|release| !release.draft));
+
+            if releases.len() >= limit {
+                break;
+            }
+
+            url = next_page_url(res.headers());
+        }
+
+        releases.truncate(limit);
+
+        Ok(releases)
+    }
+
+    /// Lists the `n` most recent releases, sorted by semver precedence of
+    /// their tag name, most recent first.
+    pub async fn latest_n(&self, n: usize) -> Result<Vec<ReleaseInfo>> {
+        let mut releases = self.list_releases(n).await?;
+
+        releases.sort_by(|a, b| {
+            let a_version = a.tag_name.trim_start_matches('v').parse::<semver::Version>();
+            let b_version = b.tag_name.trim_start_matches('v').parse::<semver::Version>();
+
+            match (a_version, b_version) {
+                (Ok(a), Ok(b)) => b.cmp(&a),
+                _ => b.tag_name.cmp(&a.tag_name),
+            }
+        });
+
+        Ok(releases)
+    }
+
+    /// Derives the `(owner, repo)` pair from the [`Client`]'s primary mirror.
+    ///
+    /// For example: `https://github.com/fluvio-community/fluvio` becomes
+    /// `("fluvio-community", "fluvio")`.
+    fn owner_and_repo(&self) -> Result<(String, String)> {
+        let mut segments = self
+            .primary_mirror()
+            .path_segments()
+            .ok_or_else(|| Error::msg("primary mirror URL has no path segments"))?;
+
+        let owner = segments
+            .next()
+            .ok_or_else(|| Error::msg("primary mirror URL is missing an owner segment"))?
+            .to_string();
+        let repo = segments
+            .next()
+            .ok_or_else(|| Error::msg("primary mirror URL is missing a repo segment"))?
+            .to_string();
+
+        Ok((owner, repo))
+    }
+
+    /// Resolves a floating [`Channel`] (`Stable`/`Latest`) to a concrete
+    /// [`Channel::Tag`] by looking up the highest non-yanked version of
+    /// `crate_name` published on crates.io. Any other [`Channel`] variant
+    /// is returned unchanged.
+    pub async fn resolve_channel(&self, channel: Channel, crate_name: &str) -> Result<Channel> {
+        use crate::htclient::ResponseExt as _;
+
+        if !matches!(channel, Channel::Stable | Channel::Latest) {
+            return Ok(channel);
+        }
+
+        let url = Url::parse(&format!(
+            "https://crates.io/api/v1/crates/{crate_name}"
+        ))?;
+        let res = self
+            .transport
+            .get_with_user_agent(url, CRATES_IO_USER_AGENT)
+            .await
+            .map_err(|err| Error::msg(err.to_string()))?;
+
+        if !res.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "crates.io responded with status code {} while resolving '{}'",
+                res.status(),
+                crate_name
+            ));
+        }
+
+        let response = res.json::<CratesIoResponse>().map_err(|err| {
+            tracing::debug!(?err, "Failed to parse crates.io response");
+            Error::msg("Failed to parse crates.io response")
+        })?;
+
+        let version = response
+            .versions
+            .into_iter()
+            .filter(|version| !version.yanked)
+            .filter_map(|version| version.num.parse::<semver::Version>().ok())
+            .max()
+            .ok_or_else(|| Error::msg(format!("No published versions found for '{crate_name}'")))?;
+
+        Ok(Channel::Tag(version))
+    }
+
+    /// Downloads the bytes at `url` and writes them to `dest`, creating any
+    /// missing parent directories.
+    pub async fn download(&self, url: &Url, dest: &std::path::Path) -> Result<()> {
+        let res = self
+            .transport
+            .get(url.clone())
+            .await
+            .map_err(|err| Error::msg(err.to_string()))?;
+
+        if !res.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "Failed to download '{}': server responded with status code {}",
+                url,
+                res.status()
+            ));
+        }
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(dest, res.bytes())?;
+
+        Ok(())
+    }
+
+    /// Checks whether the file at `path` matches the given SHA-256 `checksum`
+    pub fn checksum_matches(&self, path: &std::path::Path, checksum: &str) -> Result<bool> {
+        let bytes = std::fs::read(path)?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(&bytes);
+        let digest = hasher.finalize();
+
+        let Some(expected) = decode_checksum(checksum) else {
+            return Ok(false);
+        };
+
+        Ok(constant_time_eq(digest.as_slice(), &expected))
+    }
+
+    /// Builds the URL to fetch a [`PackageSet`] manifest from GitHub
+    /// releases, rooted at `base`.
     ///
     /// For example: https://github.com/fluvio-community/fluvio/releases/download/v0.18.1/manifest.json
-    fn make_fetch_package_set_url(&self, channel: &Channel) -> Result<Url> {
+    fn make_fetch_package_set_url(&self, base: &Url, channel: &Channel) -> Result<Url> {
         let version = match channel {
             Channel::Stable => "stable",
             Channel::Latest => "latest",
@@ -74,40 +494,204 @@ impl Client {
 
         let url = Url::parse(&format!(
             "{}/releases/download/{}/manifest.json",
-            self.api_url,
-            version
-        ))?;
+            base, version
+        ))
+        .map_err(|_| FvmError::CannotParseUrl)?;
 
         Ok(url)
     }
 }
 
+/// Verifies `data` against a detached OpenPGP `signature_armored`, using
+/// `public_key_armored` as the trusted signer.
+fn verify_signature(public_key_armored: &str, signature_armored: &str, data: &[u8]) -> Result<()> {
+    use pgp::composed::{Deserializable, DetachedSignature, SignedPublicKey};
+
+    let (public_key, _) = SignedPublicKey::from_string(public_key_armored)
+        .map_err(|err| Error::msg(format!("Invalid release signing key: {err}")))?;
+    let (signature, _) = DetachedSignature::from_string(signature_armored)
+        .map_err(|err| Error::msg(format!("Invalid detached signature: {err}")))?;
+
+    signature
+        .verify(&public_key, data)
+        .map_err(|err| Error::msg(format!("Signature verification failed: {err}")))
+}
+
+/// Extracts the `rel="next"` URL from a GitHub API `Link` header, if present
+fn next_page_url(headers: &reqwest::header::HeaderMap) -> Option<Url> {
+    let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+
+    link.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url_segment = segments.next()?.trim();
+        let is_next = segments.any(|s| s.trim() == "rel=\"next\"");
+
+        if !is_next {
+            return None;
+        }
+
+        let url_str = url_segment.trim_start_matches('<').trim_end_matches('>');
+
+        Url::parse(url_str).ok()
+    })
+}
+
+/// Decodes a manifest-published SHA-256 checksum into raw bytes, tolerating
+/// a `sha256:` prefix and either hex digit case.
+fn decode_checksum(checksum: &str) -> Option<[u8; 32]> {
+    let hex_str = checksum.strip_prefix("sha256:").unwrap_or(checksum);
+    hex::decode(hex_str).ok()?.try_into().ok()
+}
+
+/// Compares two byte slices in constant time, to avoid leaking checksum
+/// bytes through a timing side-channel.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    use std::time::Duration;
 
-    use url::Url;
+    use async_trait::async_trait;
+    use reqwest::header::{HeaderMap, HeaderValue, LINK};
+    use reqwest::StatusCode;
     use semver::Version;
+    use url::Url;
 
-    use super::{Client, Channel};
+    use crate::htclient::{HttpClientError, HttpResponse};
+
+    use super::{constant_time_eq, Channel, Client, RetryPolicy, VerifyMode};
+
+    /// A [`super::HttpTransport`] that returns a canned response for every
+    /// request, so `fetch_package_set` can be tested without a network.
+    struct MockTransport {
+        status: StatusCode,
+        body: &'static str,
+    }
+
+    #[async_trait]
+    impl super::HttpTransport for MockTransport {
+        async fn get(&self, _url: Url) -> Result<HttpResponse, HttpClientError> {
+            Ok(HttpResponse::from_parts(
+                self.status,
+                HeaderMap::new(),
+                self.body.as_bytes().to_vec(),
+            ))
+        }
+    }
+
+    /// A [`super::HttpTransport`] that responds with a server error for
+    /// `failing_host` and a successful, canned response for anything else,
+    /// used to exercise [`Client`]'s mirror fallback.
+    struct FailingHostTransport {
+        failing_host: &'static str,
+        body: &'static str,
+    }
+
+    #[async_trait]
+    impl super::HttpTransport for FailingHostTransport {
+        async fn get(&self, url: Url) -> Result<HttpResponse, HttpClientError> {
+            if url.host_str() == Some(self.failing_host) {
+                return Ok(HttpResponse::from_parts(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    HeaderMap::new(),
+                    Vec::new(),
+                ));
+            }
+
+            Ok(HttpResponse::from_parts(
+                StatusCode::OK,
+                HeaderMap::new(),
+                self.body.as_bytes().to_vec(),
+            ))
+        }
+    }
+
+    /// A [`super::HttpTransport`] that serves a distinct canned response per
+    /// URL, for tests that exercise code paths issuing more than one
+    /// request (e.g. downloading an artifact and its detached signature
+    /// after fetching a manifest). Requests for an unregistered URL get a
+    /// `404`.
+    #[derive(Default)]
+    struct RoutedTransport {
+        responses: std::collections::HashMap<String, (StatusCode, HeaderMap, Vec<u8>)>,
+    }
+
+    impl RoutedTransport {
+        fn route(mut self, url: &str, status: StatusCode, body: impl AsRef<[u8]>) -> Self {
+            self.responses
+                .insert(url.to_string(), (status, HeaderMap::new(), body.as_ref().to_vec()));
+            self
+        }
+
+        fn route_with_headers(
+            mut self,
+            url: &str,
+            status: StatusCode,
+            headers: HeaderMap,
+            body: impl AsRef<[u8]>,
+        ) -> Self {
+            self.responses
+                .insert(url.to_string(), (status, headers, body.as_ref().to_vec()));
+            self
+        }
+    }
+
+    #[async_trait]
+    impl super::HttpTransport for RoutedTransport {
+        async fn get(&self, url: Url) -> Result<HttpResponse, HttpClientError> {
+            let (status, headers, body) = self
+                .responses
+                .get(url.as_str())
+                .cloned()
+                .unwrap_or_else(|| (StatusCode::NOT_FOUND, HeaderMap::new(), Vec::new()));
+
+            Ok(HttpResponse::from_parts(status, headers, body))
+        }
+    }
+
+    #[test]
+    fn compares_checksums_in_constant_time() {
+        assert!(constant_time_eq(b"abc123", b"abc123"));
+        assert!(!constant_time_eq(b"abc123", b"abc124"));
+        assert!(!constant_time_eq(b"abc123", b"abc12"));
+    }
+
+    #[test]
+    fn derives_owner_and_repo_from_api_url() {
+        let client = Client::new("https://github.com/fluvio-community/fluvio").unwrap();
+
+        assert_eq!(
+            client.owner_and_repo().unwrap(),
+            ("fluvio-community".to_string(), "fluvio".to_string())
+        );
+    }
 
     #[test]
     fn creates_a_default_client() {
         let client = Client::new("https://github.com/fluvio-community/fluvio").unwrap();
 
         assert_eq!(
-            client.api_url,
-            Url::parse("https://github.com/fluvio-community/fluvio").unwrap()
+            client.mirrors,
+            vec![Url::parse("https://github.com/fluvio-community/fluvio").unwrap()]
         );
     }
 
     #[test]
     fn builds_urls_for_fetching_pkgsets() {
+        let client = Client::new("https://github.com/fluvio-community/fluvio").unwrap();
+        let base = client.primary_mirror().clone();
+
         // Scenario: Using Stable Channel
 
-        let client = Client::new("https://github.com/fluvio-community/fluvio").unwrap();
         let url = client
-            .make_fetch_package_set_url(&Channel::Stable)
+            .make_fetch_package_set_url(&base, &Channel::Stable)
             .unwrap();
 
         assert_eq!(
@@ -118,9 +702,8 @@ mod tests {
 
         // Scenario: Using Latest Channel
 
-        let client = Client::new("https://github.com/fluvio-community/fluvio").unwrap();
         let url = client
-            .make_fetch_package_set_url(&Channel::Latest)
+            .make_fetch_package_set_url(&base, &Channel::Latest)
             .unwrap();
 
         assert_eq!(
@@ -131,9 +714,8 @@ mod tests {
 
         // Scenario: Using Tag
 
-        let client = Client::new("https://github.com/fluvio-community/fluvio").unwrap();
         let url = client
-            .make_fetch_package_set_url(&Channel::Tag(Version::from_str("0.10.14").unwrap()))
+            .make_fetch_package_set_url(&base, &Channel::Tag(Version::from_str("0.10.14").unwrap()))
             .unwrap();
 
         assert_eq!(
@@ -142,4 +724,336 @@ mod tests {
             "failed on Scenario Using Tag"
         );
     }
+
+    #[tokio::test]
+    async fn falls_back_to_the_next_mirror_on_repeated_server_errors() {
+        let transport = FailingHostTransport {
+            failing_host: "primary.example.com",
+            body: r#"{"x86_64-unknown-linux-gnu":{"version":"0.10.14","download_url":"https://example.com/fluvio.tar.gz","checksum":"deadbeef"}}"#,
+        };
+        let policy = RetryPolicy {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(1),
+        };
+        let client = Client::with_transport_and_mirrors(
+            &[
+                "https://primary.example.com/fluvio-community/fluvio",
+                "https://mirror.example.com/fluvio-community/fluvio",
+            ],
+            transport,
+        )
+        .unwrap()
+        .with_retry_policy(policy);
+
+        let pkgset = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::None)
+            .await
+            .unwrap();
+
+        assert_eq!(pkgset.version, Version::from_str("0.10.14").unwrap());
+    }
+
+    #[tokio::test]
+    async fn fetches_a_pkgset_on_success() {
+        let transport = MockTransport {
+            status: StatusCode::OK,
+            body: r#"{"x86_64-unknown-linux-gnu":{"version":"0.10.14","download_url":"https://example.com/fluvio.tar.gz","checksum":"deadbeef"}}"#,
+        };
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let pkgset = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::None)
+            .await
+            .unwrap();
+
+        assert_eq!(pkgset.version, Version::from_str("0.10.14").unwrap());
+    }
+
+    #[tokio::test]
+    async fn fails_when_arch_is_not_in_manifest() {
+        let transport = MockTransport {
+            status: StatusCode::OK,
+            body: r#"{"x86_64-unknown-linux-gnu":{"version":"0.10.14","download_url":"https://example.com/fluvio.tar.gz","checksum":"deadbeef"}}"#,
+        };
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(&Channel::Stable, "unknown-arch", VerifyMode::None)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("unknown-arch"));
+    }
+
+    #[tokio::test]
+    async fn fails_on_malformed_manifest() {
+        let transport = MockTransport {
+            status: StatusCode::OK,
+            body: "not json",
+        };
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::None)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("failed to parse manifest file"));
+    }
+
+    #[tokio::test]
+    async fn surfaces_the_api_error_message_on_failure_status() {
+        let transport = MockTransport {
+            status: StatusCode::NOT_FOUND,
+            body: r#"{"status":404,"message":"channel not found"}"#,
+        };
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "channel not found");
+    }
+
+    const MANIFEST_URL: &str =
+        "https://github.com/fluvio-community/fluvio/releases/download/stable/manifest.json";
+    const ARTIFACT_URL: &str = "https://example.com/fluvio.tar.gz";
+    const ARTIFACT_BYTES: &[u8] = b"totally-real-artifact-bytes";
+    const ARTIFACT_CHECKSUM: &str =
+        "462a43dbde6d1cd86f77abbbfb25308dd9f080cefb882b94d4278932cad0cde9";
+
+    fn manifest_with_checksum(checksum: &str) -> String {
+        format!(
+            r#"{{"x86_64-unknown-linux-gnu":{{"version":"0.10.14","download_url":"{ARTIFACT_URL}","checksum":"{checksum}"}}}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn verifies_a_matching_checksum() {
+        let transport = RoutedTransport::default()
+            .route(MANIFEST_URL, StatusCode::OK, manifest_with_checksum(ARTIFACT_CHECKSUM))
+            .route(ARTIFACT_URL, StatusCode::OK, ARTIFACT_BYTES);
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let pkgset = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::Checksum)
+            .await
+            .unwrap();
+
+        assert_eq!(pkgset.version, Version::from_str("0.10.14").unwrap());
+    }
+
+    #[tokio::test]
+    async fn fails_checksum_verification_on_a_tampered_artifact() {
+        let transport = RoutedTransport::default()
+            .route(MANIFEST_URL, StatusCode::OK, manifest_with_checksum(ARTIFACT_CHECKSUM))
+            .route(ARTIFACT_URL, StatusCode::OK, b"tampered-bytes".as_slice());
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::Checksum)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("checksum mismatch"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_manifest_has_no_checksum() {
+        let manifest = format!(
+            r#"{{"x86_64-unknown-linux-gnu":{{"version":"0.10.14","download_url":"{ARTIFACT_URL}"}}}}"#
+        );
+        let transport = RoutedTransport::default().route(MANIFEST_URL, StatusCode::OK, manifest);
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::Checksum)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("no checksum published"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_artifact_download_returns_an_error_status() {
+        let transport = RoutedTransport::default()
+            .route(MANIFEST_URL, StatusCode::OK, manifest_with_checksum(ARTIFACT_CHECKSUM))
+            .route(ARTIFACT_URL, StatusCode::NOT_FOUND, "not found");
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(&Channel::Stable, "x86_64-unknown-linux-gnu", VerifyMode::Checksum)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("failed to download"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_signature_download_returns_an_error_status() {
+        let transport = RoutedTransport::default()
+            .route(MANIFEST_URL, StatusCode::OK, manifest_with_checksum(ARTIFACT_CHECKSUM))
+            .route(ARTIFACT_URL, StatusCode::OK, ARTIFACT_BYTES)
+            .route(
+                &format!("{ARTIFACT_URL}.sig"),
+                StatusCode::NOT_FOUND,
+                "not found",
+            );
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(
+                &Channel::Stable,
+                "x86_64-unknown-linux-gnu",
+                VerifyMode::ChecksumAndSignature,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("failed to download"));
+    }
+
+    #[tokio::test]
+    async fn fails_signature_verification_on_an_invalid_signature() {
+        let transport = RoutedTransport::default()
+            .route(MANIFEST_URL, StatusCode::OK, manifest_with_checksum(ARTIFACT_CHECKSUM))
+            .route(ARTIFACT_URL, StatusCode::OK, ARTIFACT_BYTES)
+            .route(
+                &format!("{ARTIFACT_URL}.sig"),
+                StatusCode::OK,
+                "not a real signature",
+            );
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .fetch_package_set(
+                &Channel::Stable,
+                "x86_64-unknown-linux-gnu",
+                VerifyMode::ChecksumAndSignature,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("signature verification failed"));
+    }
+
+    /// Builds a GitHub Releases API JSON entry for `tag`. Drafts are given a
+    /// `null` `published_at`, matching what GitHub actually returns.
+    fn release_json(tag: &str, draft: bool) -> String {
+        let published_at = if draft {
+            "null".to_string()
+        } else {
+            "\"2024-01-01T00:00:00Z\"".to_string()
+        };
+
+        format!(
+            r#"{{"tag_name":"{tag}","name":null,"prerelease":false,"draft":{draft},"published_at":{published_at},"assets":[]}}"#
+        )
+    }
+
+    fn link_header(next_url: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_str(&format!("<{next_url}>; rel=\"next\"")).unwrap(),
+        );
+        headers
+    }
+
+    #[tokio::test]
+    async fn lists_releases_across_pages_excluding_drafts() {
+        let first_url = "https://api.github.com/repos/fluvio-community/fluvio/releases?per_page=3";
+        let second_url = "https://api.github.com/repositories/42/releases?per_page=3&page=2";
+
+        let first_page = format!(
+            "[{},{}]",
+            release_json("v0.10.0", false),
+            release_json("v0.9.0", true)
+        );
+        let second_page = format!(
+            "[{},{}]",
+            release_json("v0.8.0", false),
+            release_json("v0.7.0", false)
+        );
+
+        let transport = RoutedTransport::default()
+            .route_with_headers(first_url, StatusCode::OK, link_header(second_url), first_page)
+            .route(second_url, StatusCode::OK, second_page);
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let releases = client.list_releases(3).await.unwrap();
+
+        assert_eq!(
+            releases.iter().map(|r| r.tag_name.as_str()).collect::<Vec<_>>(),
+            vec!["v0.10.0", "v0.8.0", "v0.7.0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn latest_n_sorts_by_semver_precedence() {
+        let url = "https://api.github.com/repos/fluvio-community/fluvio/releases?per_page=2";
+        let body = format!(
+            "[{},{}]",
+            release_json("v0.9.0", false),
+            release_json("v0.10.0", false)
+        );
+
+        let transport = RoutedTransport::default().route(url, StatusCode::OK, body);
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let releases = client.latest_n(2).await.unwrap();
+
+        assert_eq!(
+            releases.iter().map(|r| r.tag_name.as_str()).collect::<Vec<_>>(),
+            vec!["v0.10.0", "v0.9.0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_stable_to_the_highest_non_yanked_version() {
+        let url = "https://crates.io/api/v1/crates/fluvio";
+        let body = r#"{"versions":[
+            {"num":"0.10.14","yanked":false},
+            {"num":"0.10.15","yanked":true},
+            {"num":"0.9.0","yanked":false}
+        ]}"#;
+
+        let transport = RoutedTransport::default().route(url, StatusCode::OK, body);
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let channel = client.resolve_channel(Channel::Stable, "fluvio").await.unwrap();
+
+        assert_eq!(channel, Channel::Tag(Version::from_str("0.10.14").unwrap()));
+    }
+
+    #[tokio::test]
+    async fn fails_to_resolve_when_crates_io_responds_with_an_error_status() {
+        let url = "https://crates.io/api/v1/crates/fluvio";
+
+        let transport = RoutedTransport::default().route(url, StatusCode::NOT_FOUND, "not found");
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let err = client
+            .resolve_channel(Channel::Latest, "fluvio")
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("crates.io responded with status code"));
+    }
+
+    #[tokio::test]
+    async fn leaves_non_floating_channels_untouched() {
+        let transport = RoutedTransport::default();
+        let client = Client::with_transport("https://github.com/fluvio-community/fluvio", transport).unwrap();
+
+        let tag = Channel::Tag(Version::from_str("0.10.14").unwrap());
+        let channel = client.resolve_channel(tag.clone(), "fluvio").await.unwrap();
+        assert_eq!(channel, tag);
+
+        let other = Channel::Other("nightly".to_string());
+        let channel = client.resolve_channel(other.clone(), "fluvio").await.unwrap();
+        assert_eq!(channel, other);
+    }
 }
@@ -0,0 +1,9 @@
+//! Hub FVM HTTP API integration
+
+mod client;
+mod release;
+mod transport;
+
+pub use client::{ApiError, Client, VerifyMode};
+pub use release::{ReleaseAsset, ReleaseInfo};
+pub use transport::{DefaultTransport, HttpTransport};
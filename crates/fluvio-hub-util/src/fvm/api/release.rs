@@ -0,0 +1,25 @@
+//! Release metadata returned by the GitHub Releases API
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// A single release asset, as returned by the GitHub Releases API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+/// A release, as returned by the GitHub Releases API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReleaseInfo {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub prerelease: bool,
+    pub draft: bool,
+    /// `None` for draft releases, which GitHub reports with a `null`
+    /// `published_at`
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub published_at: Option<OffsetDateTime>,
+    pub assets: Vec<ReleaseAsset>,
+}
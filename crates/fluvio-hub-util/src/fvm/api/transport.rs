@@ -0,0 +1,47 @@
+//! Injectable HTTP transport for the FVM API [`Client`](super::Client)
+//!
+//! Production code uses [`DefaultTransport`], which is backed by
+//! [`crate::htclient`]. Tests can provide their own [`HttpTransport`]
+//! implementation to return canned responses without touching the network.
+
+use async_trait::async_trait;
+use url::Url;
+
+use crate::htclient::{HttpClientError, HttpResponse};
+
+/// Performs the HTTP requests needed by the FVM API client
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    /// Performs a `GET` request against `url`
+    async fn get(&self, url: Url) -> Result<HttpResponse, HttpClientError>;
+
+    /// Performs a `GET` request against `url` with a `User-Agent` header
+    /// set. Defaults to [`HttpTransport::get`] for transports that don't
+    /// need to distinguish the two.
+    async fn get_with_user_agent(
+        &self,
+        url: Url,
+        _user_agent: &str,
+    ) -> Result<HttpResponse, HttpClientError> {
+        self.get(url).await
+    }
+}
+
+/// The [`HttpTransport`] used outside of tests, backed by [`crate::htclient`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultTransport;
+
+#[async_trait]
+impl HttpTransport for DefaultTransport {
+    async fn get(&self, url: Url) -> Result<HttpResponse, HttpClientError> {
+        crate::htclient::get(url).await
+    }
+
+    async fn get_with_user_agent(
+        &self,
+        url: Url,
+        user_agent: &str,
+    ) -> Result<HttpResponse, HttpClientError> {
+        crate::htclient::get_with_user_agent(url, user_agent).await
+    }
+}
@@ -0,0 +1,52 @@
+//! Error types surfaced by the FVM client
+
+use thiserror::Error;
+
+/// Errors that can occur while fetching and verifying FVM package sets
+#[derive(Debug, Error)]
+pub enum FvmError {
+    /// The downloaded artifact's SHA-256 digest did not match the manifest
+    #[error("checksum mismatch for artifact at {url}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// The detached OpenPGP signature did not verify against the embedded
+    /// release signing key
+    #[error("signature verification failed for artifact at {url}")]
+    SignatureInvalid { url: String },
+
+    /// The manifest did not publish a checksum for the artifact, so the
+    /// requested [`VerifyMode`](crate::fvm::api::VerifyMode) could not be
+    /// honored
+    #[error("no checksum published for artifact at {url}, cannot verify")]
+    ChecksumMissing { url: String },
+
+    /// The artifact or its detached signature could not be downloaded for
+    /// verification
+    #[error("failed to download {url} for verification: server responded with status code {status}")]
+    DownloadFailed { url: String, status: u16 },
+
+    /// A base URL or one derived from it could not be parsed, or no base URL
+    /// was given
+    #[error("could not parse URL")]
+    CannotParseUrl,
+
+    /// The Hub responded with a status code that was not handled explicitly
+    #[error("server responded with unexpected status code {0}")]
+    UnexpectedStatus(u16),
+
+    /// The requested architecture is not present in the manifest
+    #[error("architecture '{0}' not found in manifest")]
+    ArchNotFound(String),
+
+    /// The manifest body could not be parsed as JSON
+    #[error("failed to parse manifest file")]
+    MalformedManifest,
+
+    /// Every configured mirror failed, after exhausting the retry policy
+    #[error("all mirrors failed")]
+    AllMirrorsFailed,
+}
@@ -0,0 +1,60 @@
+//! Domain types for the Fluvio Version Manager (FVM)
+//!
+//! These types describe installable package sets independently of how they
+//! are fetched or installed. See [`api`] for the Hub HTTP client and
+//! [`pipeline`] for turning a resolved [`PackageSet`] into binaries on disk.
+
+pub mod api;
+pub mod error;
+pub mod pipeline;
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Identifies which set of artifacts to fetch from the Hub
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Channel {
+    /// The latest tagged stable release
+    Stable,
+    /// The most recent release, stable or not
+    Latest,
+    /// A specific, pinned version
+    Tag(Version),
+    /// Any other named channel recognized by the Hub
+    Other(String),
+}
+
+/// Raw manifest entry for a single target architecture, as published in
+/// `manifest.json`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackageSetRecord {
+    pub version: Version,
+    pub download_url: Url,
+    /// SHA-256 digest (hex-encoded) of the artifact at `download_url`.
+    ///
+    /// Older manifests don't publish this field, so it defaults to empty;
+    /// verification handles that case by failing with
+    /// [`error::FvmError::ChecksumMissing`] rather than refusing to parse
+    /// the whole manifest.
+    #[serde(default)]
+    pub checksum: String,
+}
+
+/// A [`PackageSetRecord`] that has been resolved for installation
+#[derive(Debug, Clone)]
+pub struct PackageSet {
+    pub version: Version,
+    pub download_url: Url,
+    pub checksum: String,
+}
+
+impl From<PackageSetRecord> for PackageSet {
+    fn from(record: PackageSetRecord) -> Self {
+        Self {
+            version: record.version,
+            download_url: record.download_url,
+            checksum: record.checksum,
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Installation pipeline
+//!
+//! Turns a resolved [`PackageSet`] into binaries on disk: download the
+//! release archive, extract it, make the binary executable, and link it
+//! onto `PATH`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use url::Url;
+
+use super::api::Client;
+use super::PackageSet;
+
+/// A single unit of work in a [`Pipeline`]
+#[derive(Debug, Clone)]
+pub enum Step {
+    /// Downloads the artifact at `url` to `dest`, skipping the download if
+    /// `dest` already exists and its checksum matches `checksum`.
+    DownloadArtifact {
+        url: Url,
+        dest: PathBuf,
+        checksum: String,
+    },
+    /// Extracts the archive at `file` into `dest`, detecting the archive
+    /// format (`.tar.gz`/`.zip`) from `file`'s extension.
+    ExtractArchive { file: PathBuf, dest: PathBuf },
+    /// Sets the executable bit on `file`
+    MakeExecutable { file: PathBuf },
+    /// Links `target` onto `PATH` as `link`
+    LinkBinary { target: PathBuf, link: PathBuf },
+}
+
+/// A sequential list of [`Step`]s that installs a [`PackageSet`]
+#[derive(Debug, Clone, Default)]
+pub struct Pipeline {
+    steps: Vec<Step>,
+}
+
+impl Pipeline {
+    /// Builds the [`Pipeline`] that installs `package_set` into
+    /// `install_dir`.
+    pub fn from_package_set(package_set: &PackageSet, install_dir: impl AsRef<Path>) -> Self {
+        let install_dir = install_dir.as_ref();
+        let archive_name = package_set
+            .download_url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .unwrap_or("fluvio-artifact");
+
+        let archive_path = install_dir.join(archive_name);
+        let binary_path = install_dir.join("fluvio");
+        let link_path = install_dir.join("bin").join("fluvio");
+
+        let steps = vec![
+            Step::DownloadArtifact {
+                url: package_set.download_url.clone(),
+                dest: archive_path.clone(),
+                checksum: package_set.checksum.clone(),
+            },
+            Step::ExtractArchive {
+                file: archive_path,
+                dest: install_dir.to_path_buf(),
+            },
+            Step::MakeExecutable {
+                file: binary_path.clone(),
+            },
+            Step::LinkBinary {
+                target: binary_path,
+                link: link_path,
+            },
+        ];
+
+        Self { steps }
+    }
+
+    /// Runs each [`Step`] in order, reporting progress as it goes.
+    pub async fn invoke(&self, client: &Client) -> Result<()> {
+        for (idx, step) in self.steps.iter().enumerate() {
+            tracing::info!(step = idx + 1, total = self.steps.len(), ?step, "Running install step");
+            step.run(client).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Step {
+    async fn run(&self, client: &Client) -> Result<()> {
+        match self {
+            Step::DownloadArtifact { url, dest, checksum } => {
+                download_artifact(client, url, dest, checksum).await
+            }
+            Step::ExtractArchive { file, dest } => extract_archive(file, dest),
+            Step::MakeExecutable { file } => make_executable(file),
+            Step::LinkBinary { target, link } => link_binary(target, link),
+        }
+    }
+}
+
+async fn download_artifact(client: &Client, url: &Url, dest: &Path, checksum: &str) -> Result<()> {
+    if dest.exists() && client.checksum_matches(dest, checksum)? {
+        tracing::debug!(?dest, "Artifact already present with matching checksum, skipping download");
+        return Ok(());
+    }
+
+    client.download(url, dest).await
+}
+
+fn extract_archive(file: &Path, dest: &Path) -> Result<()> {
+    let file_name = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .context("archive has no file name")?;
+
+    if file_name.ends_with(".tar.gz") {
+        let tar_gz = std::fs::File::open(file)?;
+        let decoder = flate2::read::GzDecoder::new(tar_gz);
+        let mut archive = tar::Archive::new(decoder);
+        archive.unpack(dest)?;
+    } else if file_name.ends_with(".zip") {
+        let zip_file = std::fs::File::open(file)?;
+        let mut archive = zip::ZipArchive::new(zip_file)?;
+        archive.extract(dest)?;
+    } else {
+        anyhow::bail!("Unsupported archive format: {file_name}");
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn make_executable(file: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = std::fs::metadata(file)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    std::fs::set_permissions(file, permissions)?;
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_file: &Path) -> Result<()> {
+    Ok(())
+}
+
+fn link_binary(target: &Path, link: &Path) -> Result<()> {
+    if let Some(parent) = link.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    if link.exists() {
+        std::fs::remove_file(link)?;
+    }
+
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(target, link)?;
+    #[cfg(not(unix))]
+    std::fs::copy(target, link)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use semver::Version;
+    use url::Url;
+
+    use super::*;
+
+    fn sample_package_set() -> PackageSet {
+        PackageSet {
+            version: Version::from_str("0.10.14").unwrap(),
+            download_url: Url::parse(
+                "https://github.com/fluvio-community/fluvio/releases/download/v0.10.14/fluvio-x86_64-unknown-linux-gnu.tar.gz",
+            )
+            .unwrap(),
+            checksum: "deadbeef".to_string(),
+        }
+    }
+
+    #[test]
+    fn builds_steps_for_a_package_set() {
+        let package_set = sample_package_set();
+        let pipeline = Pipeline::from_package_set(&package_set, "/tmp/fvm-install");
+
+        assert_eq!(pipeline.steps.len(), 4);
+        assert!(matches!(pipeline.steps[0], Step::DownloadArtifact { .. }));
+        assert!(matches!(pipeline.steps[1], Step::ExtractArchive { .. }));
+        assert!(matches!(pipeline.steps[2], Step::MakeExecutable { .. }));
+        assert!(matches!(pipeline.steps[3], Step::LinkBinary { .. }));
+    }
+}
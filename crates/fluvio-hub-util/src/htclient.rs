@@ -0,0 +1,90 @@
+//! Minimal HTTP client used by the Hub API integrations
+//!
+//! This exists so the rest of the crate depends on a small, crate-local
+//! surface rather than directly on `reqwest`.
+
+use bytes::Bytes;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+/// Errors returned by [`get`]
+#[derive(Debug, thiserror::Error)]
+pub enum HttpClientError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+}
+
+/// A buffered HTTP response
+pub struct HttpResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl HttpResponse {
+    /// The response's HTTP status code
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The raw response body
+    pub fn bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// The response's HTTP headers
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Builds an [`HttpResponse`] from its parts, for use by test
+    /// [`crate::fvm::api::HttpTransport`] implementations that don't go
+    /// through a real HTTP request.
+    pub fn from_parts(status: StatusCode, headers: HeaderMap, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers,
+            body: body.into(),
+        }
+    }
+}
+
+/// Convenience helpers for working with an [`HttpResponse`]
+pub trait ResponseExt {
+    fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error>;
+}
+
+impl ResponseExt for HttpResponse {
+    fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+/// Performs a `GET` request against `url`, buffering the full response body
+pub async fn get(url: Url) -> Result<HttpResponse, HttpClientError> {
+    let res = reqwest::get(url).await?;
+    to_http_response(res).await
+}
+
+/// Performs a `GET` request against `url` with a `User-Agent` header set,
+/// as required by the GitHub and crates.io APIs
+pub async fn get_with_user_agent(url: Url, user_agent: &str) -> Result<HttpResponse, HttpClientError> {
+    let client = reqwest::Client::new();
+    let res = client.get(url).header("User-Agent", user_agent).send().await?;
+
+    to_http_response(res).await
+}
+
+async fn to_http_response(res: reqwest::Response) -> Result<HttpResponse, HttpClientError> {
+    let status = res.status();
+    let headers = res.headers().clone();
+    let body = res.bytes().await?;
+
+    Ok(HttpResponse {
+        status,
+        headers,
+        body,
+    })
+}
@@ -0,0 +1,4 @@
+//! Utilities for interacting with the Fluvio Hub
+
+pub mod fvm;
+pub mod htclient;